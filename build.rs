@@ -1,14 +1,34 @@
-use std::path::PathBuf;
+#[path = "build/artifacts.rs"]
+mod artifacts;
+#[path = "build/cfgs.rs"]
+mod cfgs;
+#[path = "build/env.rs"]
+mod env;
+#[path = "build/find_normal.rs"]
+mod find_normal;
+#[cfg(feature = "vendored")]
+#[path = "build/find_vendored.rs"]
+mod find_vendored;
+#[path = "build/rpath.rs"]
+mod rpath;
 
 fn main() {
-    let libclamav = pkg_config::Config::new()
-        .atleast_version("1.4.0")
-        .probe("libclamav")
-        .unwrap();
-    let mut include_paths = libclamav.include_paths.clone();
-    if let Some(val) = std::env::var_os("OPENSSL_ROOT_DIR") {
-        let mut openssl_include_dir = PathBuf::from(val);
-        openssl_include_dir.push("include");
-        include_paths.push(openssl_include_dir);
-    }
+    #[cfg(feature = "vendored")]
+    let artifacts = {
+        // CLAMAV_NO_VENDOR mirrors OPENSSL_NO_VENDOR: any value other than
+        // "0" forces the system-probe path even when the feature is on.
+        let no_vendor = std::env::var_os("CLAMAV_NO_VENDOR")
+            .map(|v| v != "0")
+            .unwrap_or(false);
+        if no_vendor {
+            find_normal::get_clamav()
+        } else {
+            find_vendored::get_clamav()
+        }
+    };
+    #[cfg(not(feature = "vendored"))]
+    let artifacts = find_normal::get_clamav();
+
+    cfgs::emit(artifacts.version);
+    rpath::emit(&artifacts.lib_dirs);
 }
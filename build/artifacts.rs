@@ -0,0 +1,12 @@
+//! The shared result type every discovery path (`find_normal`,
+//! `find_vendored`) returns, so `build.rs` can pick between them at runtime
+//! without the two modules' return types diverging into separate nominal
+//! structs.
+
+use std::path::PathBuf;
+
+pub struct Artifacts {
+    pub lib_dirs: Vec<PathBuf>,
+    pub include_dir: PathBuf,
+    pub version: Option<(u32, u32)>,
+}
@@ -0,0 +1,47 @@
+//! Emits `cargo:rustc-cfg=libclamav_X_Y` flags (and the matching
+//! `rustc-check-cfg` registrations) for every detected libclamav version, so
+//! the FFI layer can gate newer APIs behind `#[cfg(libclamav_1_5)]` instead
+//! of breaking builds against older runtimes.
+
+use std::fs;
+use std::path::Path;
+
+const KNOWN_VERSIONS: &[(u32, u32)] = &[(1, 0), (1, 1), (1, 2), (1, 3), (1, 4), (1, 5), (1, 6)];
+
+pub fn emit(version: Option<(u32, u32)>) {
+    for (major, minor) in KNOWN_VERSIONS {
+        println!("cargo:rustc-check-cfg=cfg(libclamav_{major}_{minor})");
+    }
+    println!("cargo:rustc-check-cfg=cfg(libclamav_scan_opts_v2)");
+
+    let Some(detected) = version else { return };
+    for (major, minor) in KNOWN_VERSIONS {
+        if (*major, *minor) <= detected {
+            println!("cargo:rustc-cfg=libclamav_{major}_{minor}");
+        }
+    }
+    // CL_SCAN_* bit layout used by ScanSettings grew a second generation in 1.4.
+    if detected >= (1, 4) {
+        println!("cargo:rustc-cfg=libclamav_scan_opts_v2");
+    }
+}
+
+/// Parses `X.Y.Z`-style version strings as returned by `pkg-config`.
+pub fn parse_version(raw: &str) -> Option<(u32, u32)> {
+    let mut parts = raw.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Falls back to scraping `clamav-version.h` for `CL_VERSION` when discovery
+/// didn't go through `pkg-config` (env override / vcpkg paths).
+pub fn parse_header(include_dir: &Path) -> Option<(u32, u32)> {
+    let contents = fs::read_to_string(include_dir.join("clamav-version.h")).ok()?;
+    let raw = contents
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("#define CL_VERSION"))?
+        .trim()
+        .trim_matches('"');
+    parse_version(raw)
+}
@@ -0,0 +1,25 @@
+//! Cross-compilation-aware environment lookup, mirroring `openssl-sys`'s
+//! `env(name)` helper: a target-prefixed override (e.g.
+//! `X86_64_UNKNOWN_LINUX_GNU_CLAMAV_DIR`) takes precedence over the bare
+//! variable name, so a build script invoked while cross-compiling can still
+//! be pointed at a target-specific libclamav install.
+
+use std::env;
+use std::ffi::OsString;
+
+pub fn var(name: &str) -> Option<OsString> {
+    let prefixed = format!("{}_{name}", target_prefix());
+    println!("cargo:rerun-if-env-changed={prefixed}");
+    if let Some(val) = env::var_os(&prefixed) {
+        return Some(val);
+    }
+    println!("cargo:rerun-if-env-changed={name}");
+    env::var_os(name)
+}
+
+fn target_prefix() -> String {
+    env::var("TARGET")
+        .unwrap_or_default()
+        .to_uppercase()
+        .replace(['-', '.'], "_")
+}
@@ -0,0 +1,84 @@
+//! Locates libclamav, preferring explicit environment overrides (for
+//! cross-compilation or non-standard install prefixes) and falling back to
+//! a system `pkg-config` probe.
+
+use std::path::PathBuf;
+
+use crate::{artifacts::Artifacts, cfgs, env};
+
+pub fn get_clamav() -> Artifacts {
+    if let Some(artifacts) = try_env_override() {
+        return artifacts;
+    }
+    #[cfg(target_env = "msvc")]
+    if let Some(artifacts) = try_vcpkg() {
+        return artifacts;
+    }
+    probe_pkg_config()
+}
+
+/// Looks up the `clamav` vcpkg package (and its transitive deps) on MSVC
+/// toolchains, letting Windows users `vcpkg install clamav` with no manual
+/// path wiring. Runs only after the env overrides found nothing, and falls
+/// through to the pkg-config probe on lookup failure.
+#[cfg(target_env = "msvc")]
+fn try_vcpkg() -> Option<Artifacts> {
+    let lib = vcpkg::find_package("clamav").ok()?;
+    let include_dir = lib.include_paths.first().cloned().unwrap_or_default();
+    let version = cfgs::parse_header(&include_dir);
+    Some(Artifacts {
+        lib_dirs: lib.link_paths.clone(),
+        include_dir,
+        version,
+    })
+}
+
+/// Honors `CLAMAV_LIB_DIR`/`CLAMAV_INCLUDE_DIR` (which skip discovery
+/// entirely) or a `CLAMAV_DIR` root, from which both are derived. When
+/// deriving from a root, `lib64/` is preferred over `lib/` since modern
+/// 64-bit distros install there.
+fn try_env_override() -> Option<Artifacts> {
+    let lib_dir = env::var("CLAMAV_LIB_DIR").map(PathBuf::from);
+    let include_dir = env::var("CLAMAV_INCLUDE_DIR").map(PathBuf::from);
+    let root = env::var("CLAMAV_DIR").map(PathBuf::from);
+    if lib_dir.is_none() && include_dir.is_none() && root.is_none() {
+        return None;
+    }
+
+    let root = root.unwrap_or_default();
+    let lib_dir = lib_dir.unwrap_or_else(|| {
+        [root.join("lib64"), root.join("lib")]
+            .into_iter()
+            .find(|p| p.is_dir())
+            .unwrap_or_else(|| root.join("lib"))
+    });
+    let include_dir = include_dir.unwrap_or_else(|| root.join("include"));
+    let version = cfgs::parse_header(&include_dir);
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    println!("cargo:rustc-link-lib=clamav");
+    Some(Artifacts {
+        lib_dirs: vec![lib_dir],
+        include_dir,
+        version,
+    })
+}
+
+fn probe_pkg_config() -> Artifacts {
+    let libclamav = pkg_config::Config::new()
+        .atleast_version("1.4.0")
+        .probe("libclamav")
+        .unwrap();
+    let mut include_paths = libclamav.include_paths.clone();
+    if let Some(val) = std::env::var_os("OPENSSL_ROOT_DIR") {
+        let mut openssl_include_dir = PathBuf::from(val);
+        openssl_include_dir.push("include");
+        include_paths.push(openssl_include_dir);
+    }
+    let version = cfgs::parse_version(&libclamav.version);
+    Artifacts {
+        lib_dirs: libclamav.link_paths.clone(),
+        include_dir: include_paths.into_iter().next().unwrap_or_default(),
+        version,
+    }
+}
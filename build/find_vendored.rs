@@ -0,0 +1,21 @@
+//! Compiles libclamav (and its mandatory deps) from a pinned source tarball
+//! instead of probing the system, mirroring `openssl-sys`'s vendored build.
+
+use crate::artifacts::Artifacts;
+
+/// Version of the pinned source tarball `clamav-src` builds.
+const PINNED_VERSION: (u32, u32) = (1, 4);
+
+pub fn get_clamav() -> Artifacts {
+    let artifacts = clamav_src::Build::new().build();
+    println!(
+        "cargo:rustc-link-search=native={}",
+        artifacts.lib_dir().display()
+    );
+    println!("cargo:rustc-link-lib=static=clamav");
+    Artifacts {
+        lib_dirs: vec![artifacts.lib_dir().to_path_buf()],
+        include_dir: artifacts.include_dir().to_path_buf(),
+        version: Some(PINNED_VERSION),
+    }
+}
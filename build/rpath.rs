@@ -0,0 +1,32 @@
+//! Embeds an rpath pointing at the discovered libclamav library
+//! directories, so a binary linked against a non-standard prefix (via
+//! `CLAMAV_DIR` or a vendored build) finds `libclamav.so`/`.dylib` at load
+//! time without `LD_LIBRARY_PATH`. Follows the same platform branching as
+//! rustc's own `-C rpath`: skipped entirely on Windows, and macOS gets the
+//! `@loader_path`-relative form in addition to the absolute one.
+
+use std::path::PathBuf;
+
+pub fn emit(lib_dirs: &[PathBuf]) {
+    if !should_embed() {
+        return;
+    }
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os == "windows" {
+        return;
+    }
+    for dir in lib_dirs {
+        let dir = dir.display();
+        if target_os == "macos" {
+            println!("cargo:rustc-link-arg=-Wl,-rpath,@loader_path");
+        }
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{dir}");
+    }
+}
+
+fn should_embed() -> bool {
+    let requested = std::env::var_os("CLAMAV_EMBED_RPATH")
+        .map(|v| v != "0")
+        .unwrap_or(false);
+    requested || cfg!(feature = "vendored")
+}
@@ -9,6 +9,140 @@ pub struct AppConfig {
     pub enable_shutdown_endpoint: bool,
     pub max_file_size: usize,
     pub port: u16,
+    pub s3: Option<S3Config>,
+    pub upload_constraints: Option<UploadConstraints>,
+    pub async_jobs: Option<AsyncJobConfig>,
+    #[serde(rename = "sigV4")]
+    pub sig_v4: Option<SigV4Config>,
+}
+
+/// HMAC/SigV4-style signed-request auth for `/upload`, `/scan-s3` and
+/// `/shutdown`.
+/// Leave `enabled` false (the default) for trusted internal deployments
+/// that sit behind their own gateway auth.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct SigV4Config {
+    pub enabled: bool,
+    /// Access-key id -> secret key.
+    pub credentials: std::collections::HashMap<String, String>,
+    #[serde(rename = "maxClockSkewSecs")]
+    pub max_clock_skew_secs: u64,
+}
+
+impl Default for SigV4Config {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            credentials: std::collections::HashMap::new(),
+            max_clock_skew_secs: 300,
+        }
+    }
+}
+
+/// Settings for the `/upload?async=1` + `/result/{id}` job queue.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AsyncJobConfig {
+    #[serde(rename = "resultTtlSecs")]
+    pub result_ttl_secs: u64,
+}
+
+impl Default for AsyncJobConfig {
+    fn default() -> Self {
+        Self {
+            result_ttl_secs: 300,
+        }
+    }
+}
+
+/// POST-policy style constraints enforced on each multipart field of
+/// `/upload`, modelled after S3 POST object upload policies.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct UploadConstraints {
+    #[serde(rename = "minSize")]
+    pub min_size: Option<u64>,
+    #[serde(rename = "maxSize")]
+    pub max_size: Option<u64>,
+    #[serde(rename = "allowedMimeTypes")]
+    pub allowed_mime_types: Option<Vec<String>>,
+    #[serde(rename = "deniedMimeTypes")]
+    pub denied_mime_types: Option<Vec<String>>,
+    #[serde(rename = "rejectOnMimeMismatch")]
+    pub reject_on_mime_mismatch: bool,
+}
+
+impl Default for UploadConstraints {
+    fn default() -> Self {
+        Self {
+            min_size: None,
+            max_size: None,
+            allowed_mime_types: None,
+            denied_mime_types: None,
+            reject_on_mime_mismatch: false,
+        }
+    }
+}
+
+impl UploadConstraints {
+    /// Returns a human-readable rejection reason if `sniffed`/`declared`
+    /// violate the configured MIME allow/deny list or mismatch flag.
+    ///
+    /// Fails closed: if an allow/deny list is configured but `sniffed` is
+    /// `None` (content `infer` couldn't identify, e.g. plain text), the
+    /// upload is rejected rather than silently let through the policy.
+    pub fn mime_violation(&self, sniffed: Option<&str>, declared: Option<&str>) -> Option<String> {
+        let policy_configured = self.allowed_mime_types.is_some() || self.denied_mime_types.is_some();
+        let Some(sniffed) = sniffed else {
+            return policy_configured
+                .then(|| "could not determine the uploaded file's MIME type, and an allow/deny list is configured".to_owned());
+        };
+        if let Some(denied) = &self.denied_mime_types {
+            if denied.iter().any(|m| m == sniffed) {
+                return Some(format!("sniffed MIME type '{sniffed}' is denied"));
+            }
+        }
+        if let Some(allowed) = &self.allowed_mime_types {
+            if !allowed.iter().any(|m| m == sniffed) {
+                return Some(format!("sniffed MIME type '{sniffed}' is not in the allowlist"));
+            }
+        }
+        if self.reject_on_mime_mismatch {
+            if let Some(declared) = declared {
+                if declared != sniffed {
+                    return Some(format!(
+                        "declared Content-Type '{declared}' does not match sniffed type '{sniffed}'"
+                    ));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Default S3-compatible object store used by `/scan-s3` when the request
+/// body omits `endpoint`/`accessKey`/`secretKey`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct S3Config {
+    pub endpoint: String,
+    pub region: String,
+    #[serde(rename = "accessKey")]
+    pub access_key: Option<String>,
+    #[serde(rename = "secretKey")]
+    pub secret_key: Option<String>,
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            region: "us-east-1".to_owned(),
+            access_key: None,
+            secret_key: None,
+        }
+    }
 }
 
 impl Default for AppConfig {
@@ -17,6 +151,10 @@ impl Default for AppConfig {
             enable_shutdown_endpoint: false,
             max_file_size: usize::MAX,
             port: 8000,
+            s3: None,
+            upload_constraints: None,
+            async_jobs: None,
+            sig_v4: None,
         }
     }
 }
@@ -28,9 +166,13 @@ impl fmt::Display for AppConfig {
             concat!(
                 "\tenable_shutdown_endpoint: {}\n",
                 "\tmax_file_size: {}\n",
-                "\tport: {}",
+                "\tport: {}\n",
+                "\ts3 default endpoint: {}",
             ),
-            self.enable_shutdown_endpoint, self.max_file_size, self.port,
+            self.enable_shutdown_endpoint,
+            self.max_file_size,
+            self.port,
+            self.s3.as_ref().map(|s| s.endpoint.as_str()).unwrap_or("<none>"),
         )
     }
 }
@@ -1,18 +1,23 @@
 use axum::{
     Extension, Json,
-    extract::{Multipart, multipart::Field},
-    response::Html,
+    extract::{Multipart, Query, multipart::Field},
+    response::{Html, IntoResponse, Response},
 };
 use chrono::{SecondsFormat, Utc};
 use clamav_async::fmap::Fmap;
 use digest::Digest;
 use hyper::StatusCode;
-use serde::Serialize;
-use std::{io::Write, os::unix::fs::MetadataExt, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{os::unix::fs::MetadataExt, sync::Arc};
 use tokio::{fs::File, io::AsyncReadExt};
 use tokio_stream::{StreamExt, wrappers::ReceiverStream};
+use uuid::Uuid;
 
-use crate::{app_config::AppConfig, av::AvContext};
+use crate::{
+    app_config::{AppConfig, UploadConstraints},
+    av::AvContext,
+    io_sink, job,
+};
 
 #[derive(Serialize)]
 pub struct AvResponse {
@@ -65,66 +70,253 @@ pub async fn shutdown(
     }
 }
 
+#[derive(Deserialize)]
+pub struct UploadParams {
+    #[serde(rename = "async")]
+    r#async: Option<String>,
+    #[serde(rename = "callbackUrl")]
+    callback_url: Option<String>,
+}
+
+pub enum UploadResponse {
+    Scanned(Json<AvResponse>),
+    Enqueued(Json<job::JobAccepted>),
+}
+
+impl IntoResponse for UploadResponse {
+    fn into_response(self) -> Response {
+        match self {
+            UploadResponse::Scanned(json) => json.into_response(),
+            UploadResponse::Enqueued(json) => (StatusCode::ACCEPTED, json).into_response(),
+        }
+    }
+}
+
 pub async fn upload(
+    Extension(cfg): Extension<Arc<AppConfig>>,
     Extension(ctx): Extension<Arc<AvContext>>,
+    Extension(jobs): Extension<Arc<job::JobRegistry>>,
+    Extension(job_tx): Extension<job::JobSender>,
+    Query(params): Query<UploadParams>,
     mut mp: Multipart,
-) -> Result<Json<AvResponse>, (StatusCode, String)> {
-    let mut results = Vec::new();
+) -> Result<UploadResponse, (StatusCode, String)> {
+    let constraints = cfg.upload_constraints.clone().unwrap_or_default();
+    let mut fields = Vec::new();
     while let Some(mut field) = mp.next_field().await.map_err(map_mp_error_to_400)? {
-        let mut tmp = tempfile::Builder::new()
-            .rand_bytes(12)
-            .tempfile()
-            .map_err(map_io_error_to_500)?;
-        let mut size = 0;
-        let mut crc32 = crc_fast::Digest::new(crc_fast::CrcAlgorithm::Crc32IsoHdlc);
-        let mut md5 = md5::Md5::new();
-        let mut sha256 = sha2::Sha256::new();
-        while let Some(chunk) = field.chunk().await.map_err(map_mp_error_to_400)? {
-            size += tmp.write(&chunk).map_err(map_io_error_to_500)? as u64;
-            crc32.update(&chunk);
-            md5.update(&chunk);
-            sha256.update(&chunk);
+        fields.push(persist_field(&mut field, &constraints).await?);
+    }
+
+    if matches!(params.r#async.as_deref(), Some("1" | "true")) {
+        let job_id = Uuid::new_v4();
+        jobs.insert(job_id, job::JobEntry::pending());
+        job_tx
+            .send(job::JobRequest {
+                job_id,
+                fields,
+                callback_url: params.callback_url,
+            })
+            .await
+            .map_err(|_| map_io_error_to_500(std::io::Error::other("job worker channel closed")))?;
+        return Ok(UploadResponse::Enqueued(Json(job::JobAccepted::new(job_id))));
+    }
+
+    let mut results = Vec::with_capacity(fields.len());
+    for persisted in fields {
+        results.push(scan_persisted(&ctx, &constraints, persisted).await?);
+    }
+    Ok(UploadResponse::Scanned(Json(build_response(&ctx, results))))
+}
+
+/// A source of body chunks `persist_stream` can pull from -- multipart
+/// fields and S3 object bodies alike -- without the caller buffering the
+/// whole source into memory up front.
+pub(crate) trait ChunkSource {
+    type Error;
+    async fn next_chunk(&mut self) -> Result<Option<axum::body::Bytes>, Self::Error>;
+}
+
+impl ChunkSource for &mut Field<'_> {
+    type Error = axum::extract::multipart::MultipartError;
+    async fn next_chunk(&mut self) -> Result<Option<axum::body::Bytes>, Self::Error> {
+        self.chunk().await
+    }
+}
+
+/// Reads every chunk of a multipart field into a temp file, hashing it as it
+/// streams, enforcing the configured size bounds along the way.
+pub(crate) async fn persist_field(
+    field: &mut Field<'_>,
+    constraints: &UploadConstraints,
+) -> Result<PersistedField, (StatusCode, String)> {
+    let declared_content_type = field.content_type().map(|c| c.to_owned());
+    let name = field.file_name().or(field.name()).map(|f| f.to_string());
+    persist_stream(field, name, declared_content_type, constraints, map_mp_error_to_400).await
+}
+
+/// Streams chunks into a temp file, hashing as they arrive and enforcing
+/// the configured size bounds -- the common core behind both
+/// `persist_field` (multipart `/upload`) and `/scan-s3`'s object download,
+/// so neither path buffers its whole source into memory or skips the
+/// size/MIME policy.
+pub(crate) async fn persist_stream<S: ChunkSource>(
+    mut chunks: S,
+    name: Option<String>,
+    declared_content_type: Option<String>,
+    constraints: &UploadConstraints,
+    map_chunk_error: impl Fn(S::Error) -> (StatusCode, String),
+) -> Result<PersistedField, (StatusCode, String)> {
+    let tmp = tempfile::Builder::new()
+        .rand_bytes(12)
+        .tempfile()
+        .map_err(map_io_error_to_500)?;
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::channel(32);
+    let writer = tokio::spawn(io_sink::persist(tmp.path().to_owned(), chunk_rx));
+    let mut size = 0u64;
+    let mut crc32 = crc_fast::Digest::new(crc_fast::CrcAlgorithm::Crc32IsoHdlc);
+    let mut md5 = md5::Md5::new();
+    let mut sha256 = sha2::Sha256::new();
+    loop {
+        let chunk = match chunks.next_chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(err) => return Err(map_chunk_error(err)),
+        };
+        size += chunk.len() as u64;
+        if let Some(max) = constraints.max_size {
+            if size > max {
+                writer.abort();
+                return Err((
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    format!("field exceeds the configured maximum of {max} bytes"),
+                ));
+            }
         }
-        tmp.as_file().sync_data().map_err(map_io_error_to_500)?;
-        results.push(
-            scan(
-                &ctx,
-                &field,
-                &tmp,
-                size,
-                format!("{:08x?}", crc32.finalize()),
-                const_hex::encode(md5.finalize()),
-                const_hex::encode(sha256.finalize()),
-            )
+        crc32.update(&chunk);
+        md5.update(&chunk);
+        sha256.update(&chunk);
+        chunk_tx
+            .send(chunk)
             .await
-            .map_err(map_io_error_to_500)?,
-        );
+            .map_err(|_| map_io_error_to_500(std::io::Error::other("file writer task ended early")))?;
+    }
+    drop(chunk_tx);
+    let size = writer
+        .await
+        .map_err(map_join_error_to_500)?
+        .map_err(map_io_error_to_500)?;
+    if let Some(min) = constraints.min_size {
+        if size < min {
+            return Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("field is smaller than the configured minimum of {min} bytes"),
+            ));
+        }
     }
-    Ok(Json(AvResponse {
+    Ok(PersistedField {
+        name,
+        tmp,
+        size,
+        crc32: format!("{:08x?}", crc32.finalize()),
+        md5: const_hex::encode(md5.finalize()),
+        sha256: const_hex::encode(sha256.finalize()),
+        declared_content_type,
+    })
+}
+
+/// A multipart field that has been fully persisted and hashed, awaiting a
+/// virus scan. Cheap to move across an `mpsc` channel onto the job worker.
+pub(crate) struct PersistedField {
+    pub(crate) name: Option<String>,
+    pub(crate) tmp: tempfile::NamedTempFile,
+    pub(crate) size: u64,
+    pub(crate) crc32: String,
+    pub(crate) md5: String,
+    pub(crate) sha256: String,
+    pub(crate) declared_content_type: Option<String>,
+}
+
+/// Scans an already-persisted field, but only after enforcing the MIME
+/// allow/deny policy against the sniffed content type -- rejecting a
+/// disallowed or mismatched upload with a `422` before it ever reaches the
+/// ClamAV engine, instead of paying the scan cost first.
+pub(crate) async fn scan_persisted(
+    ctx: &AvContext,
+    constraints: &UploadConstraints,
+    persisted: PersistedField,
+) -> Result<AvResult, (StatusCode, String)> {
+    let path = persisted
+        .tmp
+        .path()
+        .to_str()
+        .ok_or_else(|| std::io::Error::other("invalid path string"))
+        .map_err(map_io_error_to_500)?;
+    let content_type = detect_type(path).await.map_err(map_io_error_to_500)?;
+    if let Some(reason) =
+        constraints.mime_violation(content_type, persisted.declared_content_type.as_deref())
+    {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, reason));
+    }
+    scan_with_type(
+        ctx,
+        persisted.name,
+        &persisted.tmp,
+        persisted.size,
+        persisted.crc32,
+        persisted.md5,
+        persisted.sha256,
+        content_type,
+    )
+    .await
+    .map_err(map_io_error_to_500)
+}
+
+#[inline]
+pub(crate) fn build_response(ctx: &AvContext, results: Vec<AvResult>) -> AvResponse {
+    AvResponse {
         av_version: ctx.clamav_version.to_owned(),
         db_version: ctx.db_version,
         db_sig_count: ctx.db_sig_count,
         db_date: ctx.db_date.to_rfc3339_opts(SecondsFormat::Millis, true),
         results,
-    }))
+    }
 }
 
 #[inline]
-async fn scan(
+pub(crate) async fn scan(
     ctx: &AvContext,
-    field: &Field<'_>,
+    name: Option<String>,
     tmp: &tempfile::NamedTempFile,
     size: u64,
     crc32: String,
     md5: String,
     sha256: String,
 ) -> Result<AvResult, std::io::Error> {
-    let name = field.file_name().or(field.name()).map(|f| f.to_string());
     let path = tmp
         .path()
         .to_str()
         .ok_or_else(|| std::io::Error::other("invalid path string"))?;
     let content_type = detect_type(path).await?;
+    scan_with_type(ctx, name, tmp, size, crc32, md5, sha256, content_type).await
+}
+
+/// Runs the ClamAV engine scan against an already-sniffed content type.
+/// Split out of [`scan`] so [`scan_persisted`] can enforce the MIME policy
+/// between sniffing and the (expensive) engine scan.
+#[inline]
+async fn scan_with_type(
+    ctx: &AvContext,
+    name: Option<String>,
+    tmp: &tempfile::NamedTempFile,
+    size: u64,
+    crc32: String,
+    md5: String,
+    sha256: String,
+    content_type: Option<&'static str>,
+) -> Result<AvResult, std::io::Error> {
+    let path = tmp
+        .path()
+        .to_str()
+        .ok_or_else(|| std::io::Error::other("invalid path string"))?;
     let target = Fmap::from_file(std::fs::File::open(path)?, 0, size as usize, true);
     let settings = clamav_async::scan_settings::ScanSettings::default();
     let mut stream = ctx
@@ -187,3 +379,8 @@ fn map_mp_error_to_400(err: axum::extract::multipart::MultipartError) -> (Status
 fn map_io_error_to_500(err: std::io::Error) -> (StatusCode, String) {
     (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
 }
+
+#[inline]
+fn map_join_error_to_500(err: tokio::task::JoinError) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
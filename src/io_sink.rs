@@ -0,0 +1,69 @@
+//! Async persistence backends for streamed upload chunks.
+//!
+//! The default backend drives writes through `tokio::fs` on the regular
+//! Tokio threadpool. The `io_uring` feature swaps in a `tokio-uring` backend
+//! that submits writes as queued SQEs on a dedicated single-threaded
+//! runtime, so large-file uploads no longer block a Tokio worker on
+//! synchronous `write`/`fsync` syscalls.
+
+use std::io;
+
+use bytes::Bytes;
+
+/// Persists every chunk sent over `chunks` to `path` in order, fsyncs the
+/// result, and returns the total number of bytes written.
+#[cfg(not(feature = "io_uring"))]
+pub async fn persist(path: std::path::PathBuf, mut chunks: tokio::sync::mpsc::Receiver<Bytes>) -> io::Result<u64> {
+    use tokio::io::{AsyncWriteExt, BufWriter};
+
+    let file = tokio::fs::File::create(&path).await?;
+    let mut writer = BufWriter::new(file);
+    let mut size = 0u64;
+    while let Some(chunk) = chunks.recv().await {
+        size += chunk.len() as u64;
+        writer.write_all(&chunk).await?;
+    }
+    writer.flush().await?;
+    writer.get_ref().sync_data().await?;
+    Ok(size)
+}
+
+/// Persists every chunk sent over `chunks` to `path` in order, fsyncs the
+/// result, and returns the total number of bytes written.
+///
+/// The writes are driven on a dedicated OS thread running its own
+/// `tokio-uring` runtime, since `tokio-uring` reactors are not `Send` and
+/// cannot share the main multi-threaded Tokio runtime.
+#[cfg(feature = "io_uring")]
+pub async fn persist(path: std::path::PathBuf, chunks: tokio::sync::mpsc::Receiver<Bytes>) -> io::Result<u64> {
+    let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+    std::thread::Builder::new()
+        .name("io-uring-writer".into())
+        .spawn(move || tokio_uring::start(persist_uring(path, chunks, result_tx)))
+        .map_err(io::Error::other)?;
+    result_rx
+        .await
+        .map_err(|_| io::Error::other("io_uring writer thread terminated unexpectedly"))?
+}
+
+#[cfg(feature = "io_uring")]
+async fn persist_uring(
+    path: std::path::PathBuf,
+    mut chunks: tokio::sync::mpsc::Receiver<Bytes>,
+    result_tx: tokio::sync::oneshot::Sender<io::Result<u64>>,
+) {
+    let outcome = async {
+        let file = tokio_uring::fs::File::create(&path).await?;
+        let mut offset = 0u64;
+        while let Some(chunk) = chunks.recv().await {
+            let len = chunk.len() as u64;
+            let (res, _buf) = file.write_all_at(chunk, offset).await;
+            res?;
+            offset += len;
+        }
+        file.sync_all().await?;
+        Ok(offset)
+    }
+    .await;
+    let _ = result_tx.send(outcome);
+}
@@ -0,0 +1,131 @@
+//! Asynchronous scan jobs.
+//!
+//! `POST /upload?async=1` persists the uploaded fields and hands them off to
+//! a background worker instead of scanning inline, returning `202 Accepted`
+//! with a job id right away. `GET /result/{id}` then polls for the outcome,
+//! matching the long-poll-then-status pattern used by key-value stores.
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    Extension, Json,
+    extract::Path,
+    response::{IntoResponse, Response},
+};
+use dashmap::DashMap;
+use hyper::StatusCode;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::{
+    app_config::UploadConstraints,
+    av::AvContext,
+    controller::{self, AvResponse, PersistedField},
+};
+
+pub enum JobState {
+    Pending,
+    Done(AvResponse),
+    Failed(String),
+}
+
+pub struct JobEntry {
+    pub state: JobState,
+}
+
+impl JobEntry {
+    pub fn pending() -> Self {
+        Self {
+            state: JobState::Pending,
+        }
+    }
+}
+
+pub type JobRegistry = DashMap<Uuid, JobEntry>;
+pub type JobSender = mpsc::Sender<JobRequest>;
+
+pub struct JobRequest {
+    pub job_id: Uuid,
+    pub fields: Vec<PersistedField>,
+    pub callback_url: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct JobAccepted {
+    #[serde(rename = "jobId")]
+    job_id: Uuid,
+}
+
+impl JobAccepted {
+    pub fn new(job_id: Uuid) -> Self {
+        Self { job_id }
+    }
+}
+
+pub async fn result(
+    Extension(jobs): Extension<Arc<JobRegistry>>,
+    Path(job_id): Path<Uuid>,
+) -> Response {
+    match jobs.get(&job_id) {
+        None => StatusCode::NOT_FOUND.into_response(),
+        Some(entry) => match &entry.state {
+            JobState::Pending => StatusCode::NO_CONTENT.into_response(),
+            JobState::Failed(reason) => (StatusCode::INTERNAL_SERVER_ERROR, reason.clone()).into_response(),
+            JobState::Done(resp) => Json(resp).into_response(),
+        },
+    }
+}
+
+/// Drains `rx`, running the scan pipeline for each enqueued job and storing
+/// the outcome in `jobs` for `GET /result/{id}` to pick up. Completed
+/// entries are evicted after `result_ttl` so the registry doesn't grow
+/// unbounded when callers never poll.
+pub async fn run_worker(
+    ctx: Arc<AvContext>,
+    constraints: UploadConstraints,
+    jobs: Arc<JobRegistry>,
+    mut rx: mpsc::Receiver<JobRequest>,
+    result_ttl: Duration,
+) {
+    while let Some(job) = rx.recv().await {
+        let mut results = Vec::with_capacity(job.fields.len());
+        let mut failure = None;
+        for field in job.fields {
+            match controller::scan_persisted(&ctx, &constraints, field).await {
+                Ok(result) => results.push(result),
+                Err((_, reason)) => {
+                    failure = Some(reason);
+                    break;
+                }
+            }
+        }
+        let state = match failure {
+            Some(reason) => JobState::Failed(reason),
+            None => JobState::Done(controller::build_response(&ctx, results)),
+        };
+        if let JobState::Done(response) = &state {
+            if let Some(url) = &job.callback_url {
+                notify_callback(url, response).await;
+            }
+        }
+        jobs.insert(job.job_id, JobEntry { state });
+
+        let jobs = Arc::clone(&jobs);
+        let job_id = job.job_id;
+        tokio::spawn(async move {
+            tokio::time::sleep(result_ttl).await;
+            jobs.remove(&job_id);
+        });
+    }
+}
+
+async fn notify_callback(url: &str, response: &AvResponse) {
+    match reqwest::Client::new().post(url).json(response).send().await {
+        Ok(resp) if !resp.status().is_success() => {
+            tracing::warn!("callback to {url} returned {}", resp.status());
+        }
+        Err(err) => tracing::warn!("callback to {url} failed: {err}"),
+        Ok(_) => {}
+    }
+}
@@ -1,6 +1,10 @@
 mod app_config;
+mod auth;
 mod av;
 mod controller;
+mod io_sink;
+mod job;
+mod s3;
 
 use axum::{
     Extension, Router,
@@ -8,7 +12,7 @@ use axum::{
     routing::{get, post},
 };
 use axum_prometheus::PrometheusMetricLayer;
-use std::{net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::{
     main,
     net::TcpListener,
@@ -34,16 +38,39 @@ async fn main() {
     let (max_file_size, port) = (cfg.max_file_size, cfg.port);
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
     let (prometheus_layer, metric_handle) = PrometheusMetricLayer::pair();
+
+    let upload_constraints = cfg.upload_constraints.clone().unwrap_or_default();
+    let result_ttl = Duration::from_secs(cfg.async_jobs.clone().unwrap_or_default().result_ttl_secs);
+    let job_registry = Arc::new(job::JobRegistry::new());
+    let (job_tx, job_rx) = tokio::sync::mpsc::channel(32);
+    let ctx = Arc::new(ctx);
+    tokio::spawn(job::run_worker(
+        Arc::clone(&ctx),
+        upload_constraints,
+        Arc::clone(&job_registry),
+        job_rx,
+        result_ttl,
+    ));
+
+    let sig_v4_layer =
+        auth::SigV4AuthLayer::new(Arc::new(cfg.sig_v4.clone().unwrap_or_default()), max_file_size);
     let app = Router::new()
         .route("/health", get(|| async { "OK" }))
         .route("/metrics", get(|| async move { metric_handle.render() }))
         .route("/", get(controller::index_html))
         .route("/index.htm", get(controller::index_html))
         .route("/index.html", get(controller::index_html))
-        .route("/shutdown", post(controller::shutdown))
-        .route("/upload", post(controller::upload))
+        .route(
+            "/shutdown",
+            post(controller::shutdown).layer(sig_v4_layer.clone()),
+        )
+        .route("/upload", post(controller::upload).layer(sig_v4_layer.clone()))
+        .route("/scan-s3", post(s3::scan_s3).layer(sig_v4_layer.clone()))
+        .route("/result/{id}", get(job::result))
         .layer(Extension(Arc::new(cfg)))
-        .layer(Extension(Arc::new(ctx)))
+        .layer(Extension(ctx))
+        .layer(Extension(job_registry))
+        .layer(Extension(job_tx))
         .layer(Extension(Arc::new(Mutex::new(Some(shutdown_tx)))))
         .layer(DefaultBodyLimit::max(max_file_size))
         .layer(TraceLayer::new_for_http())
@@ -84,17 +111,22 @@ mod tests {
     use super::*;
     use axum::body::Bytes;
     use axum_test::multipart::{MultipartForm, Part};
-    use axum_test::{TestServer, expect_json};
+    use axum_test::{TestResponse, TestServer, expect_json};
+    use chrono::Utc;
+    use hyper::StatusCode;
     use serde_json::json;
 
     #[tokio::test]
     async fn upload_eicar_com_virus() {
         let cfg = app_config::load();
         let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
         let app = Router::new()
             .route("/upload", post(controller::upload))
             .layer(Extension(Arc::new(cfg)))
-            .layer(Extension(Arc::new(ctx)));
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
         let srv = TestServer::builder().mock_transport().build(app).unwrap();
         let eicar =
             Bytes::from("X5O!P%@AP[4\\PZX54(P^)7CC)7}$EICAR-STANDARD-ANTIVIRUS-TEST-FILE!$H+H*");
@@ -126,10 +158,13 @@ mod tests {
     async fn upload_eicar_com_zip_virus() {
         let cfg = app_config::load();
         let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
         let app = Router::new()
             .route("/upload", post(controller::upload))
             .layer(Extension(Arc::new(cfg)))
-            .layer(Extension(Arc::new(ctx)));
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
         let srv = TestServer::builder().mock_transport().build(app).unwrap();
         let eicar_com_zip = Bytes::from_static(&[
             0x50, 0x4b, 0x03, 0x04, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x98, 0xb8, 0x28,
@@ -175,10 +210,13 @@ mod tests {
     async fn upload_eicar_com2_zip_virus() {
         let cfg = app_config::load();
         let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
         let app = Router::new()
             .route("/upload", post(controller::upload))
             .layer(Extension(Arc::new(cfg)))
-            .layer(Extension(Arc::new(ctx)));
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
         let srv = TestServer::builder().mock_transport().build(app).unwrap();
         let eicar_com2_zip = Bytes::from_static(&[
             0x50, 0x4b, 0x03, 0x04, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x32, 0xac, 0xeb, 0x28,
@@ -232,10 +270,13 @@ mod tests {
     async fn upload_minpdf_clean() {
         let cfg = app_config::load();
         let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
         let app = Router::new()
             .route("/upload", post(controller::upload))
             .layer(Extension(Arc::new(cfg)))
-            .layer(Extension(Arc::new(ctx)));
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
         let srv = TestServer::builder().mock_transport().build(app).unwrap();
         let pdf = Bytes::from(concat!(
             "%PDF-1.\n",
@@ -272,10 +313,13 @@ mod tests {
     async fn upload_multiple_files_multiple_results() {
         let cfg = app_config::load();
         let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
         let app = Router::new()
             .route("/upload", post(controller::upload))
             .layer(Extension(Arc::new(cfg)))
-            .layer(Extension(Arc::new(ctx)));
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
         let srv = TestServer::builder().mock_transport().build(app).unwrap();
         let part1 = Part::bytes(Bytes::from("Hello world!")).file_name("helloworld.txt");
         let part2 = Part::bytes(Bytes::from("Hallo Welt!")).file_name("hallowelt.txt");
@@ -296,6 +340,137 @@ mod tests {
         }));
     }
 
+    #[tokio::test]
+    async fn upload_exceeding_max_size_is_rejected_413() {
+        let mut cfg = app_config::load();
+        cfg.upload_constraints = Some(app_config::UploadConstraints {
+            max_size: Some(5),
+            ..Default::default()
+        });
+        let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let part = Part::bytes(Bytes::from("Hello world!")).file_name("helloworld.txt");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload").multipart(form).await;
+        resp.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn upload_below_min_size_is_rejected_422() {
+        let mut cfg = app_config::load();
+        cfg.upload_constraints = Some(app_config::UploadConstraints {
+            min_size: Some(1000),
+            ..Default::default()
+        });
+        let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let part = Part::bytes(Bytes::from("Hello world!")).file_name("helloworld.txt");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload").multipart(form).await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn upload_denied_mime_type_is_rejected_422() {
+        let mut cfg = app_config::load();
+        cfg.upload_constraints = Some(app_config::UploadConstraints {
+            denied_mime_types: Some(vec!["application/pdf".to_owned()]),
+            ..Default::default()
+        });
+        let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let pdf = Bytes::from(concat!(
+            "%PDF-1.\n",
+            "1 0 obj<</Pages 2 0 R>>endobj\n",
+            "2 0 obj<</Kids[3 0 R]/Count 1>>endobj\n",
+            "3 0 obj<</Parent 2 0 R>>endobj\n",
+            "trailer <</Root 1 0 R>>",
+        ));
+        let part = Part::bytes(pdf).file_name("min.pdf");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload").multipart(form).await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn upload_mime_not_in_allowlist_is_rejected_422() {
+        let mut cfg = app_config::load();
+        cfg.upload_constraints = Some(app_config::UploadConstraints {
+            allowed_mime_types: Some(vec!["text/plain".to_owned()]),
+            ..Default::default()
+        });
+        let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let pdf = Bytes::from(concat!(
+            "%PDF-1.\n",
+            "1 0 obj<</Pages 2 0 R>>endobj\n",
+            "2 0 obj<</Kids[3 0 R]/Count 1>>endobj\n",
+            "3 0 obj<</Parent 2 0 R>>endobj\n",
+            "trailer <</Root 1 0 R>>",
+        ));
+        let part = Part::bytes(pdf).file_name("min.pdf");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload").multipart(form).await;
+        resp.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn upload_allowed_mime_type_passes() {
+        let mut cfg = app_config::load();
+        cfg.upload_constraints = Some(app_config::UploadConstraints {
+            allowed_mime_types: Some(vec!["application/pdf".to_owned()]),
+            ..Default::default()
+        });
+        let ctx = av::load_context().await;
+        let (job_tx, _job_rx) = tokio::sync::mpsc::channel(1);
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(ctx)))
+            .layer(Extension(Arc::new(job::JobRegistry::new())))
+            .layer(Extension(job_tx));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let pdf = Bytes::from(concat!(
+            "%PDF-1.\n",
+            "1 0 obj<</Pages 2 0 R>>endobj\n",
+            "2 0 obj<</Kids[3 0 R]/Count 1>>endobj\n",
+            "3 0 obj<</Parent 2 0 R>>endobj\n",
+            "trailer <</Root 1 0 R>>",
+        ));
+        let part = Part::bytes(pdf).file_name("min.pdf");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload").multipart(form).await;
+        resp.assert_status_ok();
+    }
+
     #[tokio::test]
     async fn index_html() {
         let cfg = app_config::load();
@@ -328,6 +503,10 @@ mod tests {
             enable_shutdown_endpoint: true,
             max_file_size: 42,
             port: 8000,
+            s3: None,
+            upload_constraints: None,
+            async_jobs: None,
+            sig_v4: None,
         };
         let (shutdown_tx, _) = oneshot::channel::<()>();
         let app = Router::new()
@@ -338,4 +517,253 @@ mod tests {
         let resp = srv.post("/shutdown").await;
         resp.assert_status_success();
     }
+
+    /// Builds the `Authorization` header value a conforming client would
+    /// send for the given signed headers (including `x-amz-date`),
+    /// independently of `auth::verify`, so these tests
+    /// exercise the server against a from-scratch signer rather than
+    /// round-tripping the same code.
+    fn sign(
+        method: &str,
+        path: &str,
+        signed_headers: &[(&str, &str)],
+        body: &[u8],
+        access_key: &str,
+        secret: &str,
+    ) -> String {
+        use hmac::{Hmac, Mac};
+        use sha2::{Digest, Sha256};
+
+        let mut names: Vec<&str> = signed_headers.iter().map(|(name, _)| *name).collect();
+        names.sort_unstable();
+        let canonical_headers = names
+            .iter()
+            .map(|name| {
+                let value = signed_headers.iter().find(|(n, _)| n == name).unwrap().1;
+                format!("{}:{}", name.to_lowercase(), value.trim())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signed_header_names = signed_headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let body_hash = const_hex::encode(Sha256::digest(body));
+        let canonical_request =
+            format!("{method}\n{path}\n\n{canonical_headers}\n{signed_header_names}\n{body_hash}");
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(canonical_request.as_bytes());
+        let signature = const_hex::encode(mac.finalize().into_bytes());
+        format!(
+            "REEFSPECT-HMAC-SHA256 Credential={access_key}, SignedHeaders={signed_header_names}, Signature={signature}"
+        )
+    }
+
+    fn sig_v4_cfg(access_key: &str, secret: &str) -> app_config::SigV4Config {
+        let mut credentials = std::collections::HashMap::new();
+        credentials.insert(access_key.to_owned(), secret.to_owned());
+        app_config::SigV4Config {
+            enabled: true,
+            credentials,
+            max_clock_skew_secs: 300,
+        }
+    }
+
+    fn shutdown_app_with_sig_v4(sig_v4: app_config::SigV4Config) -> Router {
+        shutdown_app_with_sig_v4_and_limit(sig_v4, usize::MAX)
+    }
+
+    fn shutdown_app_with_sig_v4_and_limit(sig_v4: app_config::SigV4Config, max_body_bytes: usize) -> Router {
+        let cfg = app_config::AppConfig {
+            enable_shutdown_endpoint: true,
+            sig_v4: Some(sig_v4.clone()),
+            ..app_config::AppConfig::default()
+        };
+        let (shutdown_tx, _) = oneshot::channel::<()>();
+        let sig_v4_layer = auth::SigV4AuthLayer::new(Arc::new(sig_v4), max_body_bytes);
+        Router::new()
+            .route(
+                "/shutdown",
+                post(controller::shutdown).layer(sig_v4_layer),
+            )
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(Arc::new(Mutex::new(Some(shutdown_tx)))))
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_valid_signature_is_accepted() {
+        let app = shutdown_app_with_sig_v4(sig_v4_cfg("test-key", "test-secret"));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let headers = [("host", "localhost"), ("x-amz-date", date.as_str())];
+        let auth = sign("POST", "/shutdown", &headers, b"", "test-key", "test-secret");
+        let resp = srv
+            .post("/shutdown")
+            .add_header("host", "localhost")
+            .add_header("x-amz-date", date.as_str())
+            .add_header("authorization", auth.as_str())
+            .await;
+        resp.assert_status_success();
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_bad_signature_is_forbidden() {
+        let app = shutdown_app_with_sig_v4(sig_v4_cfg("test-key", "test-secret"));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let headers = [("host", "localhost"), ("x-amz-date", date.as_str())];
+        let auth = sign("POST", "/shutdown", &headers, b"", "test-key", "wrong-secret");
+        let resp = srv
+            .post("/shutdown")
+            .add_header("host", "localhost")
+            .add_header("x-amz-date", date.as_str())
+            .add_header("authorization", auth.as_str())
+            .await;
+        resp.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_missing_authorization_is_forbidden() {
+        let app = shutdown_app_with_sig_v4(sig_v4_cfg("test-key", "test-secret"));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let resp = srv.post("/shutdown").await;
+        resp.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_expired_timestamp_is_forbidden() {
+        let app = shutdown_app_with_sig_v4(sig_v4_cfg("test-key", "test-secret"));
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let stale_date = (Utc::now() - chrono::Duration::seconds(3600))
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string();
+        let headers = [("host", "localhost"), ("x-amz-date", stale_date.as_str())];
+        let auth = sign("POST", "/shutdown", &headers, b"", "test-key", "test-secret");
+        let resp = srv
+            .post("/shutdown")
+            .add_header("host", "localhost")
+            .add_header("x-amz-date", stale_date.as_str())
+            .add_header("authorization", auth.as_str())
+            .await;
+        resp.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn shutdown_body_over_limit_is_rejected_413_before_signature_check() {
+        let app = shutdown_app_with_sig_v4_and_limit(sig_v4_cfg("test-key", "test-secret"), 4);
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let resp = srv.post("/shutdown").text("way too much body").await;
+        resp.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn shutdown_disabled_sig_v4_skips_verification() {
+        let app = shutdown_app_with_sig_v4(app_config::SigV4Config::default());
+        let srv = TestServer::builder().mock_transport().build(app).unwrap();
+        let resp = srv.post("/shutdown").await;
+        resp.assert_status_success();
+    }
+
+    /// Wires `/upload`, `/result/{id}` and a spawned [`job::run_worker`]
+    /// together the way `main` does, with `result_ttl` under the caller's
+    /// control so eviction timing can be tested without sleeping on the
+    /// production default.
+    async fn async_job_app(result_ttl: Duration) -> TestServer {
+        let cfg = app_config::load();
+        let constraints = cfg.upload_constraints.clone().unwrap_or_default();
+        let job_registry = Arc::new(job::JobRegistry::new());
+        let (job_tx, job_rx) = tokio::sync::mpsc::channel(32);
+        let ctx = Arc::new(av::load_context().await);
+        tokio::spawn(job::run_worker(
+            Arc::clone(&ctx),
+            constraints,
+            Arc::clone(&job_registry),
+            job_rx,
+            result_ttl,
+        ));
+        let app = Router::new()
+            .route("/upload", post(controller::upload))
+            .route("/result/{id}", get(job::result))
+            .layer(Extension(Arc::new(cfg)))
+            .layer(Extension(ctx))
+            .layer(Extension(job_registry))
+            .layer(Extension(job_tx));
+        TestServer::builder().mock_transport().build(app).unwrap()
+    }
+
+    async fn enqueue_async_upload(srv: &TestServer) -> Uuid {
+        let part = Part::bytes(Bytes::from("Hello world!")).file_name("helloworld.txt");
+        let form = MultipartForm::new().add_part("name", part);
+        let resp = srv.post("/upload?async=true").multipart(form).await;
+        resp.assert_status(StatusCode::ACCEPTED);
+        resp.json::<serde_json::Value>()["jobId"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    async fn poll_until_ready(srv: &TestServer, job_id: Uuid) -> TestResponse {
+        for _ in 0..50 {
+            let resp = srv.get(&format!("/result/{job_id}")).await;
+            if resp.status_code() != StatusCode::NO_CONTENT {
+                return resp;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("job {job_id} never completed");
+    }
+
+    #[tokio::test]
+    async fn async_upload_result_is_retained_across_repeated_polls() {
+        let srv = async_job_app(Duration::from_secs(300)).await;
+        let job_id = enqueue_async_upload(&srv).await;
+
+        let first = poll_until_ready(&srv, job_id).await;
+        first.assert_status_ok();
+        first.assert_json(&json!({
+            "avVersion": expect_json::string(),
+            "dbVersion": expect_json::integer(),
+            "dbSignatureCount": expect_json::integer(),
+            "dbDate": expect_json::iso_date_time(),
+            "results": [{
+                "name": "helloworld.txt",
+                "size": 12,
+                "crc32": expect_json::string(),
+                "md5": expect_json::string(),
+                "sha256": expect_json::string(),
+                "contentType": null,
+                "dateScanned": expect_json::iso_date_time(),
+                "result": "CLEAN",
+                "signature": null,
+            }]
+        }));
+
+        // Regression test for a1544de: the first successful read of a
+        // completed job used to remove it from the registry, so a second
+        // poll would 404 instead of returning the same result again.
+        let second = srv.get(&format!("/result/{job_id}")).await;
+        second.assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn async_upload_result_is_evicted_after_ttl() {
+        let srv = async_job_app(Duration::from_millis(50)).await;
+        let job_id = enqueue_async_upload(&srv).await;
+
+        poll_until_ready(&srv, job_id).await.assert_status_ok();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let resp = srv.get(&format!("/result/{job_id}")).await;
+        resp.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn result_of_unknown_job_id_is_404() {
+        let srv = async_job_app(Duration::from_secs(300)).await;
+        let resp = srv.get(&format!("/result/{}", Uuid::new_v4())).await;
+        resp.assert_status_not_found();
+    }
 }
@@ -0,0 +1,111 @@
+//! Pull-and-scan endpoint for S3-compatible object stores.
+//!
+//! `POST /scan-s3` lets a storage gateway (e.g. one fronted by Garage or
+//! another S3-compatible store) hand reefspect an object reference instead
+//! of re-uploading the bytes through the browser. The object is streamed
+//! down, hashed and scanned through the same pipeline as `/upload`.
+
+use std::sync::Arc;
+
+use aws_sdk_s3::config::{Credentials, Region};
+use aws_sdk_s3::primitives::{ByteStream, ByteStreamError};
+use axum::{Extension, Json};
+use futures_util::TryStreamExt;
+use hyper::StatusCode;
+use serde::Deserialize;
+
+use crate::{app_config::AppConfig, av::AvContext, controller};
+
+impl controller::ChunkSource for ByteStream {
+    type Error = ByteStreamError;
+    async fn next_chunk(&mut self) -> Result<Option<axum::body::Bytes>, Self::Error> {
+        self.try_next().await
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ScanS3Request {
+    endpoint: Option<String>,
+    bucket: String,
+    key: String,
+    region: Option<String>,
+    #[serde(rename = "accessKey")]
+    access_key: Option<String>,
+    #[serde(rename = "secretKey")]
+    secret_key: Option<String>,
+}
+
+pub async fn scan_s3(
+    Extension(cfg): Extension<Arc<AppConfig>>,
+    Extension(ctx): Extension<Arc<AvContext>>,
+    Json(req): Json<ScanS3Request>,
+) -> Result<Json<controller::AvResponse>, (StatusCode, String)> {
+    let defaults = cfg.s3.clone().unwrap_or_default();
+    let endpoint = req
+        .endpoint
+        .or(Some(defaults.endpoint).filter(|e| !e.is_empty()))
+        .ok_or_else(|| map_bad_request("no S3 endpoint configured or provided"))?;
+    let region = req.region.unwrap_or(defaults.region);
+    let access_key = req.access_key.or(defaults.access_key);
+    let secret_key = req.secret_key.or(defaults.secret_key);
+
+    let mut builder = aws_sdk_s3::config::Builder::new()
+        .endpoint_url(&endpoint)
+        .region(Region::new(region))
+        .force_path_style(true);
+    if let (Some(access_key), Some(secret_key)) = (access_key, secret_key) {
+        builder = builder.credentials_provider(Credentials::new(
+            access_key, secret_key, None, None, "reefspect-scan-s3",
+        ));
+    }
+    let client = aws_sdk_s3::Client::from_conf(builder.build());
+
+    let bucket = req.bucket.clone();
+    let key = req.key.clone();
+    let object = client
+        .get_object()
+        .bucket(&req.bucket)
+        .key(&req.key)
+        .send()
+        .await
+        .map_err(|err| {
+            tracing::warn!("GetObject failed for s3://{bucket}/{key}: {err}");
+            (StatusCode::BAD_GATEWAY, "failed to fetch object from S3".to_owned())
+        })?;
+    let etag = object.e_tag().map(|t| t.trim_matches('"').to_owned());
+    let declared_content_type = object.content_type().map(|c| c.to_owned());
+
+    let constraints = cfg.upload_constraints.clone().unwrap_or_default();
+    let bucket = req.bucket.clone();
+    let key = req.key.clone();
+    let persisted = controller::persist_stream(
+        object.body,
+        Some(req.key),
+        declared_content_type,
+        &constraints,
+        move |err| {
+            tracing::warn!("failed reading s3://{bucket}/{key} body: {err}");
+            (StatusCode::BAD_GATEWAY, "failed to read object body from S3".to_owned())
+        },
+    )
+    .await?;
+    if let Some(etag) = &etag {
+        if !etag.contains('-') && *etag != persisted.md5 {
+            tracing::warn!(
+                "ETag mismatch for s3://{}/{}: etag={} computed={}",
+                req.bucket,
+                req.key,
+                etag,
+                persisted.md5,
+            );
+        }
+    }
+
+    let result = controller::scan_persisted(&ctx, &constraints, persisted).await?;
+    Ok(Json(controller::build_response(&ctx, vec![result])))
+}
+
+#[inline]
+fn map_bad_request(msg: &str) -> (StatusCode, String) {
+    (StatusCode::BAD_REQUEST, msg.to_owned())
+}